@@ -0,0 +1,96 @@
+use bdk_wallet::bitcoin::Network;
+
+use std::str::FromStr;
+
+/// A portable, vendor-neutral descriptor wallet backup, following the standard export shape
+/// used by other descriptor-based wallets: descriptors, network, a birthday height recorded at
+/// export time, and a free-form label.
+#[derive(Clone, Debug)]
+pub struct WalletExport {
+    pub descriptor: String,
+    pub change_descriptor: Option<String>,
+    pub network: Network,
+    pub blockheight: u32,
+    pub label: String,
+}
+
+/// Wire format for `WalletExport`, kept separate so `Network` (which doesn't implement
+/// `serde::Serialize`/`Deserialize` here) round-trips through its `Display`/`FromStr` string
+/// form instead.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct WalletExportJson {
+    descriptor: String,
+    change_descriptor: Option<String>,
+    network: String,
+    blockheight: u32,
+    label: String,
+}
+
+impl WalletExport {
+    /// Serialize this export to the standard JSON wallet-export format.
+    pub fn as_json(&self) -> String {
+        let wire = WalletExportJson {
+            descriptor: self.descriptor.clone(),
+            change_descriptor: self.change_descriptor.clone(),
+            network: self.network.to_string(),
+            blockheight: self.blockheight,
+            label: self.label.clone(),
+        };
+        serde_json::to_string(&wire).expect("WalletExportJson is always serializable")
+    }
+
+    /// Parse a standard wallet-export JSON blob back into its fields.
+    pub fn from_json(json: String) -> Result<WalletExport, WalletExportError> {
+        let wire: WalletExportJson =
+            serde_json::from_str(&json).map_err(|e| WalletExportError::InvalidJson {
+                error_message: e.to_string(),
+            })?;
+        let network =
+            Network::from_str(&wire.network).map_err(|_| WalletExportError::InvalidNetwork {
+                network: wire.network,
+            })?;
+
+        Ok(WalletExport {
+            descriptor: wire.descriptor,
+            change_descriptor: wire.change_descriptor,
+            network,
+            blockheight: wire.blockheight,
+            label: wire.label,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WalletExportError {
+    #[error("invalid wallet export json: {error_message}")]
+    InvalidJson { error_message: String },
+    #[error("invalid network: {network}")]
+    InvalidNetwork { network: String },
+    #[error("supplied {which} does not match the wallet's own descriptor")]
+    DescriptorMismatch { which: &'static str },
+}
+
+#[cfg(test)]
+mod wallet_export_tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip_preserves_embedded_quotes() {
+        let export = WalletExport {
+            descriptor: r#"wpkh([abcd1234/84'/1'/0']tpub.../0/*)"#.to_string(),
+            change_descriptor: Some(r#"wpkh([abcd1234/84'/1'/0']tpub.../1/*)"#.to_string()),
+            network: Network::Testnet,
+            blockheight: 2_100_000,
+            label: r#"My "main" wallet"#.to_string(),
+        };
+
+        let json = export.as_json();
+        let parsed = WalletExport::from_json(json).expect("round trip should parse");
+
+        assert_eq!(parsed.label, export.label);
+        assert_eq!(parsed.descriptor, export.descriptor);
+        assert_eq!(parsed.change_descriptor, export.change_descriptor);
+        assert_eq!(parsed.network, export.network);
+        assert_eq!(parsed.blockheight, export.blockheight);
+    }
+}
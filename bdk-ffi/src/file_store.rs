@@ -0,0 +1,72 @@
+use bdk_wallet::rusqlite::Connection as BdkConnection;
+use bdk_wallet::PersistedWallet;
+use bdk_wallet::Wallet as BdkWallet;
+
+use std::sync::{Mutex, MutexGuard};
+
+/// Append-only changeset file persister, as an alternative to SQLite for targets that can't
+/// ship it (e.g. mobile/embedded).
+pub(crate) type BdkFileStore = bdk_file_store::Store<bdk_wallet::ChangeSet>;
+
+/// Magic bytes written at the start of a file-store database to identify its format.
+const FILE_STORE_MAGIC: &[u8] = b"bdk_ffi_file_store_1";
+
+/// The persistence backend a `Wallet` was constructed with.
+///
+/// `PersistedWallet` is generic over its persister, matching upstream `bdk_chain::persist`,
+/// where wallet, file_store, and sqlite all implement a common persist trait. Everything but
+/// construction and persisting itself operates on the non-generic wallet underneath, so this
+/// enum only needs to be matched on at those boundaries.
+pub(crate) enum WalletBackend {
+    Sqlite(PersistedWallet<BdkConnection>),
+    File(PersistedWallet<BdkFileStore>),
+}
+
+impl std::ops::Deref for WalletBackend {
+    type Target = BdkWallet;
+
+    fn deref(&self) -> &BdkWallet {
+        match self {
+            WalletBackend::Sqlite(wallet) => wallet,
+            WalletBackend::File(wallet) => wallet,
+        }
+    }
+}
+
+impl std::ops::DerefMut for WalletBackend {
+    fn deref_mut(&mut self) -> &mut BdkWallet {
+        match self {
+            WalletBackend::Sqlite(wallet) => wallet,
+            WalletBackend::File(wallet) => wallet,
+        }
+    }
+}
+
+/// An append-only changeset file store, as an alternative to `Connection` (SQLite) for wallet
+/// persistence. Aggregates `ChangeSet`s to disk and replays them on load, so it works on
+/// targets that can't ship SQLite.
+pub struct FileStoreConnection(pub(crate) Mutex<BdkFileStore>);
+
+impl FileStoreConnection {
+    /// Open or create a file-store database at `path`.
+    pub fn new(path: String) -> Result<Self, FileStoreError> {
+        let db = BdkFileStore::open_or_create_new(FILE_STORE_MAGIC, path).map_err(|e| {
+            FileStoreError::Open {
+                error_message: e.to_string(),
+            }
+        })?;
+        Ok(FileStoreConnection(Mutex::new(db)))
+    }
+
+    pub(crate) fn get_store(&self) -> MutexGuard<BdkFileStore> {
+        self.0.lock().expect("file store")
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStoreError {
+    #[error("failed to open file store: {error_message}")]
+    Open { error_message: String },
+    #[error("failed to write to file store: {error_message}")]
+    Write { error_message: String },
+}
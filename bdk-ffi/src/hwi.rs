@@ -0,0 +1,168 @@
+#![cfg(feature = "hwi")]
+
+use bdk_wallet::bitcoin::bip32::Fingerprint;
+use bdk_wallet::bitcoin::Psbt as BdkPsbt;
+use hwi::error::Error as HwiLibError;
+use hwi::types::{HWIChain, HWIDevice};
+use hwi::HWIClient;
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A hardware wallet discovered on the host, as reported by HWI.
+#[derive(Clone, Debug)]
+pub struct HwiDevice {
+    pub device_type: String,
+    pub model: String,
+    pub fingerprint: String,
+    pub needs_passphrase: bool,
+}
+
+impl From<HWIDevice> for HwiDevice {
+    fn from(device: HWIDevice) -> Self {
+        HwiDevice {
+            device_type: device.device_type,
+            model: device.model,
+            fingerprint: device.fingerprint.to_string(),
+            needs_passphrase: device.needs_passphrase,
+        }
+    }
+}
+
+/// Where to look for hardware signing devices: real USB/HID devices, or HWI's own simulator
+/// (`hwi.emulators`), which CI runs instead of physical hardware.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum HwiDeviceSource {
+    #[default]
+    Physical,
+    Simulator,
+}
+
+/// Enumerate the hardware signing devices HWI can currently see. Pass `HwiDeviceSource::Simulator`
+/// in CI/tests to target the HWI emulator explicitly instead of relying on physical hardware
+/// being plugged in.
+pub fn enumerate_hwi_devices(source: HwiDeviceSource) -> Result<Vec<HwiDevice>, HwiError> {
+    let devices = match source {
+        HwiDeviceSource::Physical => HWIClient::enumerate()?,
+        HwiDeviceSource::Simulator => HWIClient::enumerate()?
+            .into_iter()
+            .filter(|d| d.as_ref().is_ok_and(|d| d.device_type.contains("simulator")))
+            .collect(),
+    };
+    Ok(devices
+        .into_iter()
+        .filter_map(|device| device.ok())
+        .map(HwiDevice::from)
+        .collect())
+}
+
+/// Signs PSBTs with an external hardware device (Ledger/Trezor/Coldcard) through HWI.
+///
+/// Used via `Wallet::sign_with_hardware_signer`, which only ever locks the PSBT being signed,
+/// not the wallet, since a device round-trip can take seconds and shouldn't block every other
+/// wallet call for that long.
+#[derive(Debug)]
+pub struct HwiSigner {
+    fingerprint: Fingerprint,
+    chain: HWIChain,
+    source: HwiDeviceSource,
+}
+
+impl HwiSigner {
+    /// Build a signer bound to the device with the given master key fingerprint.
+    ///
+    /// `chain` must match the network the wallet operates on; HWI refuses to sign otherwise.
+    /// Pass `HwiDeviceSource::Simulator` to target HWI's emulator (used by CI) instead of a
+    /// physical device.
+    pub fn new(
+        fingerprint: String,
+        chain: HwiChain,
+        source: HwiDeviceSource,
+    ) -> Result<Arc<Self>, HwiError> {
+        let fingerprint = Fingerprint::from_str(&fingerprint)
+            .map_err(|_| HwiError::InvalidFingerprint { fingerprint })?;
+        Ok(Arc::new(HwiSigner {
+            fingerprint,
+            chain: chain.into(),
+            source,
+        }))
+    }
+
+    fn find_device(&self) -> Result<HWIDevice, HwiLibError> {
+        let candidates = match self.source {
+            HwiDeviceSource::Physical => HWIClient::enumerate()?,
+            HwiDeviceSource::Simulator => HWIClient::enumerate()?
+                .into_iter()
+                .filter(|d| d.as_ref().is_ok_and(|d| d.device_type.contains("simulator")))
+                .collect(),
+        };
+        candidates
+            .into_iter()
+            .filter_map(|device| device.ok())
+            .find(|device| device.fingerprint == self.fingerprint)
+            .ok_or(HwiLibError::Hwi("device not connected".to_string()))
+    }
+
+    /// Query the device for signatures over `psbt` and merge them back in, in place.
+    pub(crate) fn sign_psbt(&self, psbt: &mut BdkPsbt) -> Result<(), HwiError> {
+        let device = self.find_device().map_err(HwiError::from)?;
+        let client = HWIClient::get_client(&device, false, self.chain.clone())
+            .map_err(HwiError::from)?;
+        let signed = client.sign_tx(psbt).map_err(HwiError::from)?;
+        *psbt = signed.psbt;
+        Ok(())
+    }
+}
+
+/// Network selector for HWI, mirroring `hwi::types::HWIChain` without pulling the HWI types
+/// across the FFI boundary directly.
+#[derive(Clone, Debug)]
+pub enum HwiChain {
+    Main,
+    Test,
+    Signet,
+    Regtest,
+}
+
+impl From<HwiChain> for HWIChain {
+    fn from(chain: HwiChain) -> Self {
+        match chain {
+            HwiChain::Main => HWIChain::Main,
+            HwiChain::Test => HWIChain::Test,
+            HwiChain::Signet => HWIChain::Signet,
+            HwiChain::Regtest => HWIChain::Regtest,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HwiError {
+    #[error("invalid master key fingerprint: {fingerprint}")]
+    InvalidFingerprint { fingerprint: String },
+    #[error("hwi error: {error_message}")]
+    Hwi { error_message: String },
+}
+
+impl From<HwiLibError> for HwiError {
+    fn from(error: HwiLibError) -> Self {
+        HwiError::Hwi {
+            error_message: error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod hwi_tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "requires the HWI simulator (hwi.emulators) running locally"]
+    fn enumerate_simulator_devices() {
+        let devices = enumerate_hwi_devices(HwiDeviceSource::Simulator)
+            .expect("simulator enumeration should succeed when the emulator is running");
+        assert!(
+            !devices.is_empty(),
+            "expected at least one simulator device to be reported"
+        );
+    }
+}
@@ -4,20 +4,32 @@ use crate::error::{
     CalculateFeeError, CannotConnectError, CreateTxError, CreateWithPersistError,
     LoadWithPersistError, SignerError, SqliteError, TxidParseError,
 };
+use crate::export::{WalletExport, WalletExportError};
+use crate::file_store::{BdkFileStore, FileStoreConnection, FileStoreError, WalletBackend};
+#[cfg(feature = "hwi")]
+use crate::hwi::{HwiError, HwiSigner};
 use crate::store::Connection;
 use crate::types::{AddressInfo, Balance, CanonicalTx, LocalOutput, ScriptAmount};
 use crate::types::{FullScanRequestBuilder, SyncRequestBuilder, Update};
 
 use bdk_wallet::bitcoin::amount::Amount as BdkAmount;
 use bdk_wallet::bitcoin::Network;
+use bdk_wallet::bitcoin::script::PushBytesBuf;
 use bdk_wallet::bitcoin::Psbt as BdkPsbt;
 use bdk_wallet::bitcoin::ScriptBuf as BdkScriptBuf;
+use bdk_wallet::bitcoin::Transaction as BdkTransaction;
+use bdk_wallet::miniscript::psbt::PsbtExt;
 use bdk_wallet::bitcoin::{OutPoint as BdkOutPoint, Sequence, Txid};
+use bdk_wallet::coin_selection::{
+    BranchAndBoundCoinSelection, CoinSelectionAlgorithm as BdkCoinSelectionAlgorithm,
+    LargestFirstCoinSelection, OldestFirstCoinSelection, SingleRandomDraw,
+};
 use bdk_wallet::rusqlite::Connection as BdkConnection;
-use bdk_wallet::tx_builder::ChangeSpendPolicy;
+use bdk_wallet::tx_builder::{ChangeSpendPolicy, TxBuilder as BdkTxBuilder};
 use bdk_wallet::PersistedWallet;
+use bdk_wallet::SignOptions as BdkSignOptions;
 use bdk_wallet::Wallet as BdkWallet;
-use bdk_wallet::{KeychainKind, SignOptions};
+use bdk_wallet::KeychainKind;
 
 use std::borrow::BorrowMut;
 use std::collections::HashSet;
@@ -25,7 +37,7 @@ use std::str::FromStr;
 use std::sync::{Arc, Mutex, MutexGuard};
 
 pub struct Wallet {
-    inner_mutex: Mutex<PersistedWallet<BdkConnection>>,
+    inner_mutex: Mutex<WalletBackend>,
 }
 
 impl Wallet {
@@ -46,7 +58,30 @@ impl Wallet {
                 .create_wallet(db)?;
 
         Ok(Wallet {
-            inner_mutex: Mutex::new(wallet),
+            inner_mutex: Mutex::new(WalletBackend::Sqlite(wallet)),
+        })
+    }
+
+    /// Create a new wallet persisted to an append-only changeset file instead of SQLite, for
+    /// targets like mobile/embedded that can't ship SQLite.
+    pub fn new_with_file_store(
+        descriptor: Arc<Descriptor>,
+        change_descriptor: Arc<Descriptor>,
+        network: Network,
+        connection: Arc<FileStoreConnection>,
+    ) -> Result<Self, CreateWithPersistError> {
+        let descriptor = descriptor.to_string_with_secret();
+        let change_descriptor = change_descriptor.to_string_with_secret();
+        let mut binding = connection.get_store();
+        let db: &mut BdkFileStore = binding.borrow_mut();
+
+        let wallet: PersistedWallet<BdkFileStore> =
+            BdkWallet::create(descriptor, change_descriptor)
+                .network(network)
+                .create_wallet(db)?;
+
+        Ok(Wallet {
+            inner_mutex: Mutex::new(WalletBackend::File(wallet)),
         })
     }
 
@@ -67,11 +102,33 @@ impl Wallet {
             .ok_or(LoadWithPersistError::CouldNotLoad)?;
 
         Ok(Wallet {
-            inner_mutex: Mutex::new(wallet),
+            inner_mutex: Mutex::new(WalletBackend::Sqlite(wallet)),
         })
     }
 
-    pub(crate) fn get_wallet(&self) -> MutexGuard<PersistedWallet<BdkConnection>> {
+    /// Load a wallet previously created with `new_with_file_store`.
+    pub fn load_with_file_store(
+        descriptor: Arc<Descriptor>,
+        change_descriptor: Arc<Descriptor>,
+        connection: Arc<FileStoreConnection>,
+    ) -> Result<Wallet, LoadWithPersistError> {
+        let descriptor = descriptor.to_string_with_secret();
+        let change_descriptor = change_descriptor.to_string_with_secret();
+        let mut binding = connection.get_store();
+        let db: &mut BdkFileStore = binding.borrow_mut();
+
+        let wallet: PersistedWallet<BdkFileStore> = BdkWallet::load()
+            .descriptor(KeychainKind::External, Some(descriptor))
+            .descriptor(KeychainKind::Internal, Some(change_descriptor))
+            .load_wallet(db)?
+            .ok_or(LoadWithPersistError::CouldNotLoad)?;
+
+        Ok(Wallet {
+            inner_mutex: Mutex::new(WalletBackend::File(wallet)),
+        })
+    }
+
+    pub(crate) fn get_wallet(&self) -> MutexGuard<WalletBackend> {
         self.inner_mutex.lock().expect("wallet")
     }
 
@@ -98,17 +155,139 @@ impl Wallet {
         self.get_wallet().is_mine(script.0.clone())
     }
 
+    /// Sign `psbt` with the signers registered in this wallet's descriptor (private keys held
+    /// in software). This does **not** include hardware signers: an HWI-backed `HwiSigner` is
+    /// never registered on the wallet and will not fire here, since invoking it would mean doing
+    /// device I/O while holding the wallet's mutex. Call `sign_with_hardware_signer` explicitly
+    /// to sign with a hardware device instead.
     pub(crate) fn sign(
         &self,
         psbt: Arc<Psbt>,
-        // sign_options: Option<SignOptions>,
+        sign_options: Option<SignOptions>,
     ) -> Result<bool, SignerError> {
         let mut psbt = psbt.0.lock().unwrap();
+        let sign_options = sign_options
+            .map(BdkSignOptions::from)
+            .unwrap_or_else(BdkSignOptions::default);
         self.get_wallet()
-            .sign(&mut psbt, SignOptions::default())
+            .sign(&mut psbt, sign_options)
             .map_err(SignerError::from)
     }
 
+    /// Finalize `psbt`'s inputs in place, turning each input's partial signatures into a final
+    /// `scriptSig`/witness, independent of signing itself. Returns whether every input could be
+    /// finalized; a PSBT that still needs more signatures is left unfinalized rather than
+    /// erroring, mirroring `sign`'s return convention.
+    pub fn finalize_psbt(&self, psbt: Arc<Psbt>) -> Result<bool, SignerError> {
+        let secp = bdk_wallet::bitcoin::secp256k1::Secp256k1::verification_only();
+        let mut psbt = psbt.0.lock().unwrap();
+        match psbt.finalize_mut(&secp) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Verify that `psbt` fully satisfies `tx` against this wallet's known UTXOs before
+    /// broadcast: every input's witness/scriptSig must satisfy the corresponding descriptor via
+    /// miniscript satisfaction, `tx` must be exactly the transaction `psbt` finalizes to (so a
+    /// psbt that satisfies its own embedded data can't be paired with an unrelated `tx`), and
+    /// input amounts must cover outputs plus a sane fee. Returns a structured error identifying
+    /// the problem, so apps can catch malformed, under-signed, or mismatched PSBTs locally
+    /// instead of relying on node rejection.
+    pub fn verify_tx(&self, tx: &Transaction, psbt: Arc<Psbt>) -> Result<(), VerifyTxError> {
+        let wallet = self.get_wallet();
+        let secp = bdk_wallet::bitcoin::secp256k1::Secp256k1::verification_only();
+        let psbt = psbt.0.lock().unwrap();
+        let bdk_tx: BdkTransaction = tx.into();
+
+        let mut input_value = BdkAmount::ZERO;
+        for (index, input) in psbt.inputs.iter().enumerate() {
+            let utxo = input
+                .witness_utxo
+                .clone()
+                .or_else(|| {
+                    input.non_witness_utxo.as_ref().and_then(|prev_tx| {
+                        bdk_tx
+                            .input
+                            .get(index)
+                            .and_then(|txin| prev_tx.output.get(txin.previous_output.vout as usize))
+                            .cloned()
+                    })
+                })
+                .ok_or(VerifyTxError::MissingUtxo {
+                    input_index: index as u32,
+                })?;
+
+            // Cross-check inputs the wallet tracks against its own recorded UTXO value, so a
+            // caller-supplied PSBT can't claim a different amount than what the wallet actually
+            // saw on chain for one of its own outputs.
+            if let Some(txin) = bdk_tx.input.get(index) {
+                if let Some(known) = wallet.get_utxo(txin.previous_output) {
+                    if known.txout.value != utxo.value {
+                        return Err(VerifyTxError::Invalid {
+                            input_index: index as u32,
+                            error_message: format!(
+                                "psbt claims {} sats but wallet recorded {} sats for this UTXO",
+                                utxo.value.to_sat(),
+                                known.txout.value.to_sat()
+                            ),
+                        });
+                    }
+                }
+            }
+
+            input_value += utxo.value;
+        }
+
+        // Finalizing a clone runs the real miniscript satisfaction check for every input: it can
+        // only succeed if each input's signatures/witness data actually satisfy the descriptor
+        // the UTXO was locked with. An empty or garbage witness fails here instead of silently
+        // passing a value-only check.
+        let mut finalized = psbt.clone();
+        finalized
+            .finalize_mut(&secp)
+            .map_err(|errors| first_finalize_error(errors))?;
+
+        // Extract the transaction the psbt actually finalizes to (inputs, sequences, outputs,
+        // *and* witness/scriptSig) and require it to match `tx` exactly. Without this, `tx` and
+        // `psbt` are never actually tied together: a psbt that satisfies its own embedded
+        // unsigned_tx says nothing about whether `tx` spends the same inputs or pays the same
+        // outputs.
+        let extracted = finalized
+            .extract_tx()
+            .map_err(|e| VerifyTxError::Invalid {
+                input_index: 0,
+                error_message: e.to_string(),
+            })?;
+        if extracted != bdk_tx {
+            return Err(VerifyTxError::TxPsbtMismatch);
+        }
+
+        let output_value: BdkAmount = bdk_tx.output.iter().map(|o| o.value).sum();
+        if input_value < output_value {
+            return Err(VerifyTxError::InsufficientInputValue {
+                input_value: input_value.to_sat(),
+                output_value: output_value.to_sat(),
+            });
+        }
+
+        // A "sane fee" ceiling catches the classic missing-change-output bug, where the entire
+        // input value (minus a token output) ends up paid as fee: an implied fee rate this high
+        // is never intentional, regardless of how small the transaction is.
+        let fee = input_value - output_value;
+        let vsize = bdk_tx.vsize().max(1) as u64;
+        let fee_rate_sat_per_vb = fee.to_sat() / vsize;
+        if fee_rate_sat_per_vb > MAX_SANE_FEE_RATE_SAT_PER_VB {
+            return Err(VerifyTxError::ExcessiveFee {
+                fee_sats: fee.to_sat(),
+                fee_rate_sat_per_vb,
+                max_fee_rate_sat_per_vb: MAX_SANE_FEE_RATE_SAT_PER_VB,
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn sent_and_received(&self, tx: &Transaction) -> SentAndReceivedValues {
         let (sent, received) = self.get_wallet().sent_and_received(&tx.into());
         SentAndReceivedValues {
@@ -163,15 +342,109 @@ impl Wallet {
         Arc::new(SyncRequestBuilder(Mutex::new(Some(builder))))
     }
 
+    /// Export the wallet's descriptors, network, and the wallet's current tip height as a
+    /// portable JSON blob in the standard descriptor-wallet export shape, so it can be handed to
+    /// a different wallet application as a vendor-neutral backup or migration mechanism.
+    ///
+    /// `blockheight` is recorded for informational purposes only (the height the wallet had
+    /// synced to at export time) — this call does not itself bound or resume any scan.
+    /// `descriptor`/`change_descriptor` must be the same descriptors the wallet was created or
+    /// loaded with, checked against the wallet's own public descriptors; a mismatch returns
+    /// `WalletExportError::DescriptorMismatch` rather than silently exporting a backup that
+    /// doesn't describe this wallet. Private key material is omitted unless `include_private` is
+    /// set, since the export is meant to be shared or stored outside the signing device by
+    /// default.
+    pub fn export(
+        &self,
+        label: String,
+        descriptor: Arc<Descriptor>,
+        change_descriptor: Arc<Descriptor>,
+        include_private: bool,
+    ) -> Result<WalletExport, WalletExportError> {
+        let wallet = self.get_wallet();
+        if descriptor.to_string() != wallet.public_descriptor(KeychainKind::External).to_string()
+        {
+            return Err(WalletExportError::DescriptorMismatch { which: "descriptor" });
+        }
+        if change_descriptor.to_string()
+            != wallet.public_descriptor(KeychainKind::Internal).to_string()
+        {
+            return Err(WalletExportError::DescriptorMismatch {
+                which: "change_descriptor",
+            });
+        }
+        let blockheight = wallet.latest_checkpoint().height();
+        drop(wallet);
+
+        let (descriptor, change_descriptor) = if include_private {
+            (
+                descriptor.to_string_with_secret(),
+                change_descriptor.to_string_with_secret(),
+            )
+        } else {
+            (descriptor.to_string(), change_descriptor.to_string())
+        };
+
+        Ok(WalletExport {
+            descriptor,
+            change_descriptor: Some(change_descriptor),
+            network: self.network(),
+            blockheight,
+            label,
+        })
+    }
+
+    /// Sign `psbt` with an external hardware device (Ledger/Trezor/Coldcard) via HWI.
+    ///
+    /// Deliberately does not go through `Wallet::sign`/the wallet's signer container: device
+    /// I/O can take seconds (or block indefinitely on an unplugged/slow device), and this way
+    /// it only ever holds the PSBT's own lock, not the wallet's — every other wallet call
+    /// (`balance`, `list_unspent`, etc.) keeps working while a hardware signature is pending.
+    #[cfg(feature = "hwi")]
+    pub fn sign_with_hardware_signer(
+        &self,
+        psbt: Arc<Psbt>,
+        signer: Arc<HwiSigner>,
+    ) -> Result<(), HwiError> {
+        let mut psbt = psbt.0.lock().unwrap();
+        signer.sign_psbt(&mut psbt)
+    }
+
     // pub fn persist(&self, connection: Connection) -> Result<bool, FfiGenericError> {
     pub fn persist(&self, connection: Arc<Connection>) -> Result<bool, SqliteError> {
         let mut binding = connection.get_store();
         let db: &mut BdkConnection = binding.borrow_mut();
-        self.get_wallet()
-            .persist(db)
-            .map_err(|e| SqliteError::Sqlite {
-                rusqlite_error: e.to_string(),
-            })
+        match &mut *self.get_wallet() {
+            WalletBackend::Sqlite(wallet) => {
+                wallet.persist(db).map_err(|e| SqliteError::Sqlite {
+                    rusqlite_error: e.to_string(),
+                })
+            }
+            WalletBackend::File(_) => Err(SqliteError::Sqlite {
+                rusqlite_error: "wallet was opened with a file-store connection; call persist_to_file_store instead".to_string(),
+            }),
+        }
+    }
+
+    /// Persist this wallet's pending changes to the append-only changeset file it was created
+    /// or loaded with via `new_with_file_store`/`load_with_file_store`.
+    pub fn persist_to_file_store(
+        &self,
+        connection: Arc<FileStoreConnection>,
+    ) -> Result<bool, FileStoreError> {
+        let mut binding = connection.get_store();
+        let db: &mut BdkFileStore = binding.borrow_mut();
+        match &mut *self.get_wallet() {
+            WalletBackend::File(wallet) => {
+                wallet.persist(db).map_err(|e| FileStoreError::Write {
+                    error_message: e.to_string(),
+                })
+            }
+            WalletBackend::Sqlite(_) => Err(FileStoreError::Write {
+                error_message: "wallet was opened with a sqlite connection; call persist instead"
+                    .to_string(),
+            }),
+        }
     }
 }
 
@@ -192,7 +465,8 @@ pub struct TxBuilder {
     pub(crate) drain_wallet: bool,
     pub(crate) drain_to: Option<BdkScriptBuf>,
     pub(crate) rbf: Option<RbfValue>,
-    // pub(crate) data: Vec<u8>,
+    pub(crate) data: Option<Vec<u8>>,
+    pub(crate) coin_selection: Option<CoinSelectionAlgorithm>,
 }
 
 impl TxBuilder {
@@ -208,10 +482,34 @@ impl TxBuilder {
             drain_wallet: false,
             drain_to: None,
             rbf: None,
-            // data: Vec::new(),
+            data: None,
+            coin_selection: None,
         }
     }
 
+    /// Choose the coin-selection algorithm used to pick inputs beyond any manually selected ones.
+    ///
+    /// Defaults to the wallet's built-in coin selector (branch-and-bound with a
+    /// single-random-draw fallback) when this is never called.
+    pub(crate) fn coin_selection(&self, algorithm: CoinSelectionAlgorithm) -> Arc<Self> {
+        Arc::new(TxBuilder {
+            coin_selection: Some(algorithm),
+            ..self.clone()
+        })
+    }
+
+    /// Add data to the transaction as an `OP_RETURN` output.
+    ///
+    /// `data` must be no longer than 80 bytes, which is the de facto standardness limit
+    /// enforced by the Bitcoin Core relay policy. Passing an empty `Vec` still produces an
+    /// empty `OP_RETURN` output, distinct from never calling this at all.
+    pub(crate) fn add_data(&self, data: Vec<u8>) -> Arc<Self> {
+        Arc::new(TxBuilder {
+            data: Some(data),
+            ..self.clone()
+        })
+    }
+
     pub(crate) fn add_recipient(&self, script: &Script, amount: Arc<Amount>) -> Arc<Self> {
         let mut recipients: Vec<(BdkScriptBuf, BdkAmount)> = self.recipients.clone();
         recipients.append(&mut vec![(script.0.clone(), amount.0)]);
@@ -332,10 +630,10 @@ impl TxBuilder {
         })
     }
 
-    pub(crate) fn finish(&self, wallet: &Arc<Wallet>) -> Result<Arc<Psbt>, CreateTxError> {
-        // TODO: I had to change the wallet here to be mutable. Why is that now required with the 1.0 API?
-        let mut wallet = wallet.get_wallet();
-        let mut tx_builder = wallet.build_tx();
+    fn apply_params<Cs: BdkCoinSelectionAlgorithm>(
+        &self,
+        tx_builder: &mut BdkTxBuilder<'_, Cs>,
+    ) -> Result<(), CreateTxError> {
         for (script, amount) in &self.recipients {
             tx_builder.add_recipient(script.clone(), *amount);
         }
@@ -376,18 +674,86 @@ impl TxBuilder {
                 }
             }
         }
+        if let Some(data) = &self.data {
+            if data.len() > 80 {
+                return Err(CreateTxError::Generic {
+                    error_message: format!(
+                        "OP_RETURN data must be at most 80 bytes, got {}",
+                        data.len()
+                    ),
+                });
+            }
+            let push_bytes = PushBytesBuf::try_from(data.clone()).map_err(|_| {
+                CreateTxError::Generic {
+                    error_message: "OP_RETURN data could not be converted to push bytes"
+                        .to_string(),
+                }
+            })?;
+            tx_builder.add_data(&push_bytes);
+        }
 
-        let psbt = tx_builder.finish().map_err(CreateTxError::from)?;
+        Ok(())
+    }
+
+    pub(crate) fn finish(&self, wallet: &Arc<Wallet>) -> Result<Arc<Psbt>, CreateTxError> {
+        // TODO: I had to change the wallet here to be mutable. Why is that now required with the 1.0 API?
+        let mut wallet = wallet.get_wallet();
+        let psbt = match &self.coin_selection {
+            None => {
+                let mut tx_builder = wallet.build_tx();
+                self.apply_params(&mut tx_builder)?;
+                tx_builder.finish().map_err(CreateTxError::from)?
+            }
+            Some(CoinSelectionAlgorithm::LargestFirst) => {
+                let mut tx_builder = wallet.build_tx().coin_selection(LargestFirstCoinSelection);
+                self.apply_params(&mut tx_builder)?;
+                tx_builder.finish().map_err(CreateTxError::from)?
+            }
+            Some(CoinSelectionAlgorithm::OldestFirst) => {
+                let mut tx_builder = wallet.build_tx().coin_selection(OldestFirstCoinSelection);
+                self.apply_params(&mut tx_builder)?;
+                tx_builder.finish().map_err(CreateTxError::from)?
+            }
+            Some(CoinSelectionAlgorithm::BranchAndBound) => {
+                let mut tx_builder = wallet
+                    .build_tx()
+                    .coin_selection(BranchAndBoundCoinSelection::default());
+                self.apply_params(&mut tx_builder)?;
+                tx_builder.finish().map_err(CreateTxError::from)?
+            }
+            Some(CoinSelectionAlgorithm::SingleRandomDraw) => {
+                let mut tx_builder = wallet.build_tx().coin_selection(SingleRandomDraw);
+                self.apply_params(&mut tx_builder)?;
+                tx_builder.finish().map_err(CreateTxError::from)?
+            }
+        };
 
         Ok(Arc::new(psbt.into()))
     }
 }
 
+/// Strategy used to pick which UTXOs fund a transaction.
+///
+/// `BranchAndBound` performs a depth-first search over the UTXO set trying to hit the target
+/// (recipients + fee) within the cost of an extra change output, minimizing waste defined as
+/// `sum(selected.effective_value) - target + sum(fee_to_spend_each_input)`. It falls back to
+/// `SingleRandomDraw` when the search exhausts its iteration budget without an exact-enough
+/// match.
+#[derive(Clone, Debug)]
+pub enum CoinSelectionAlgorithm {
+    LargestFirst,
+    OldestFirst,
+    BranchAndBound,
+    SingleRandomDraw,
+}
+
 #[derive(Clone)]
 pub(crate) struct BumpFeeTxBuilder {
     pub(crate) txid: String,
     pub(crate) fee_rate: Arc<FeeRate>,
     pub(crate) rbf: Option<RbfValue>,
+    pub(crate) utxos: Vec<OutPoint>,
+    pub(crate) fee_reduction_output: Option<BdkScriptBuf>,
 }
 
 impl BumpFeeTxBuilder {
@@ -396,6 +762,8 @@ impl BumpFeeTxBuilder {
             txid,
             fee_rate,
             rbf: None,
+            utxos: Vec::new(),
+            fee_reduction_output: None,
         }
     }
 
@@ -413,12 +781,46 @@ impl BumpFeeTxBuilder {
         })
     }
 
+    /// Supply additional UTXOs the fee bump may spend when the original transaction's change
+    /// is insufficient to cover the higher fee.
+    pub(crate) fn add_utxos(&self, mut outpoints: Vec<OutPoint>) -> Arc<Self> {
+        let mut utxos = self.utxos.to_vec();
+        utxos.append(&mut outpoints);
+        Arc::new(Self {
+            utxos,
+            ..self.clone()
+        })
+    }
+
+    /// Mark `script` as allowed to shrink to absorb the fee increase, leaving every other
+    /// output's amount untouched. `script` must match one of the original transaction's own
+    /// outputs (its change output, or a recipient output for CPFP-style rescues); bdk's
+    /// `allow_shrinking` is what actually implements this, unlike a plain `drain_to` (which only
+    /// controls where newly-created change goes, not which existing output may shrink).
+    pub(crate) fn set_fee_reduction_output(&self, script: &Script) -> Arc<Self> {
+        Arc::new(Self {
+            fee_reduction_output: Some(script.0.clone()),
+            ..self.clone()
+        })
+    }
+
     pub(crate) fn finish(&self, wallet: &Arc<Wallet>) -> Result<Arc<Psbt>, CreateTxError> {
         let txid = Txid::from_str(self.txid.as_str()).map_err(|_| CreateTxError::UnknownUtxo {
             outpoint: self.txid.clone(),
         })?;
         let mut wallet = wallet.get_wallet();
-        let mut tx_builder = wallet.build_fee_bump(txid).map_err(CreateTxError::from)?;
+        let mut tx_builder = wallet.build_fee_bump(txid).map_err(|e| {
+            if matches!(e, bdk_wallet::error::BuildFeeBumpError::IrreplaceableTransaction(_)) {
+                CreateTxError::Generic {
+                    error_message: format!(
+                        "original transaction {} is not replaceable (RBF was not signaled)",
+                        self.txid
+                    ),
+                }
+            } else {
+                CreateTxError::from(e)
+            }
+        })?;
         tx_builder.fee_rate(self.fee_rate.0);
         if let Some(rbf) = &self.rbf {
             match *rbf {
@@ -430,6 +832,22 @@ impl BumpFeeTxBuilder {
                 }
             }
         }
+        if !self.utxos.is_empty() {
+            let bdk_utxos: Vec<BdkOutPoint> = self.utxos.iter().map(BdkOutPoint::from).collect();
+            tx_builder
+                .add_utxos(&bdk_utxos)
+                .map_err(CreateTxError::from)?;
+        }
+        if let Some(script) = &self.fee_reduction_output {
+            tx_builder.allow_shrinking(script.clone()).map_err(|_| {
+                CreateTxError::Generic {
+                    error_message:
+                        "the chosen fee-reduction output is not part of the original transaction, \
+                         so no output can absorb the fee bump"
+                            .to_string(),
+                }
+            })?;
+        }
         let psbt: BdkPsbt = tx_builder.finish()?;
 
         Ok(Arc::new(psbt.into()))
@@ -440,3 +858,127 @@ pub enum RbfValue {
     Default,
     Value(u32),
 }
+
+#[cfg(test)]
+mod bump_fee_tx_builder_tests {
+    use super::*;
+
+    // This only exercises the builder's own state transitions (choosing a fee-reduction output
+    // doesn't clobber unrelated fields like `utxos`/`rbf`). Confirming that `allow_shrinking`
+    // actually leaves a multi-recipient transaction's other outputs untouched end-to-end needs a
+    // real wallet + chain-state harness, which this source snapshot doesn't have available.
+    #[test]
+    fn set_fee_reduction_output_only_sets_that_field() {
+        let script = Script(BdkScriptBuf::new());
+        let fee_rate = Arc::new(FeeRate(bdk_wallet::bitcoin::FeeRate::from_sat_per_vb_unchecked(
+            1,
+        )));
+        let builder = BumpFeeTxBuilder::new("0".repeat(64), fee_rate)
+            .add_utxos(vec![])
+            .enable_rbf();
+
+        let with_target = builder.set_fee_reduction_output(&script);
+
+        assert_eq!(with_target.fee_reduction_output, Some(script.0.clone()));
+        assert!(with_target.utxos.is_empty());
+        assert!(matches!(with_target.rbf, Some(RbfValue::Default)));
+    }
+}
+
+/// Map the first per-input failure out of `finalize_mut`'s error list into our structured error,
+/// so callers learn which input actually failed to satisfy its descriptor.
+fn first_finalize_error(errors: Vec<bdk_wallet::miniscript::psbt::Error>) -> VerifyTxError {
+    match errors.into_iter().next() {
+        Some(bdk_wallet::miniscript::psbt::Error::InputError(input_error, input_index)) => {
+            VerifyTxError::Invalid {
+                input_index: input_index as u32,
+                error_message: input_error.to_string(),
+            }
+        }
+        Some(other) => VerifyTxError::Invalid {
+            input_index: 0,
+            error_message: other.to_string(),
+        },
+        None => VerifyTxError::Invalid {
+            input_index: 0,
+            error_message: "psbt finalization failed for an unknown input".to_string(),
+        },
+    }
+}
+
+/// Sanity ceiling on the implied fee rate `verify_tx` will accept, in sat/vB. Real fee markets
+/// have never sustained anything close to this; a psbt implying a higher rate almost always
+/// means a missing change output, not an intentional fee.
+const MAX_SANE_FEE_RATE_SAT_PER_VB: u64 = 2_000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyTxError {
+    #[error("input {input_index} has no witness_utxo or non_witness_utxo to verify against")]
+    MissingUtxo { input_index: u32 },
+    #[error("input {input_index} failed verification: {error_message}")]
+    Invalid {
+        input_index: u32,
+        error_message: String,
+    },
+    #[error("tx is not the transaction psbt finalizes to (different inputs, sequences, outputs, or witness data)")]
+    TxPsbtMismatch,
+    #[error("input value {input_value} sats is less than output value {output_value} sats")]
+    InsufficientInputValue {
+        input_value: u64,
+        output_value: u64,
+    },
+    #[error("implied fee rate {fee_rate_sat_per_vb} sat/vB ({fee_sats} sats total) exceeds the sanity ceiling of {max_fee_rate_sat_per_vb} sat/vB -- is the change output missing?")]
+    ExcessiveFee {
+        fee_sats: u64,
+        fee_rate_sat_per_vb: u64,
+        max_fee_rate_sat_per_vb: u64,
+    },
+}
+
+/// Options for a signing operation, mirroring `bdk_wallet::SignOptions`.
+///
+/// These control how aggressively the signer trusts unverified data and whether the resulting
+/// PSBT inputs are finalized, which matters for PSBT workflows that must stay open for further
+/// signers or for coordinating multi-party signing.
+#[derive(Clone, Debug)]
+pub struct SignOptions {
+    pub trust_witness_utxo: bool,
+    pub assume_height: Option<u32>,
+    pub allow_all_sighashes: bool,
+    pub try_finalize: bool,
+    pub sign_with_tap_internal_key: bool,
+    pub allow_grinding: bool,
+}
+
+impl From<SignOptions> for BdkSignOptions {
+    fn from(sign_options: SignOptions) -> Self {
+        BdkSignOptions {
+            trust_witness_utxo: sign_options.trust_witness_utxo,
+            assume_height: sign_options.assume_height,
+            allow_all_sighashes: sign_options.allow_all_sighashes,
+            try_finalize: sign_options.try_finalize,
+            sign_with_tap_internal_key: sign_options.sign_with_tap_internal_key,
+            allow_grinding: sign_options.allow_grinding,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for SignOptions {
+    fn default() -> Self {
+        BdkSignOptions::default().into()
+    }
+}
+
+impl From<BdkSignOptions> for SignOptions {
+    fn from(sign_options: BdkSignOptions) -> Self {
+        SignOptions {
+            trust_witness_utxo: sign_options.trust_witness_utxo,
+            assume_height: sign_options.assume_height,
+            allow_all_sighashes: sign_options.allow_all_sighashes,
+            try_finalize: sign_options.try_finalize,
+            sign_with_tap_internal_key: sign_options.sign_with_tap_internal_key,
+            allow_grinding: sign_options.allow_grinding,
+        }
+    }
+}